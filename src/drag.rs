@@ -0,0 +1,295 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use peniko::kurbo::Point;
+
+use crate::event::{Event, EventListener, EventPropagation, PointerEvent};
+use crate::id::ViewId;
+
+/// The distance the pointer must travel past its pointer-down position, in pixels,
+/// before a [`draggable`](DragExt::draggable) payload starts a drag session.
+const DRAG_START_THRESHOLD: f64 = 4.0;
+
+/// The phase of an in-progress, in-application drag session.
+///
+/// Unlike [`FileDragEvent`](crate::dropped_file::FileDragEvent), a session carries a typed
+/// Rust payload rather than paths from the OS, so it can be used to reorder list items,
+/// drag a tab between docks, and similar widget-to-widget interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragPhase {
+    /// The session has just started on the originating (source) view.
+    Start,
+    /// The pointer has entered a potential drop target while a session is active.
+    Enter,
+    /// The pointer is moving over a potential drop target while a session is active.
+    Over,
+    /// The pointer has left a potential drop target while a session is active.
+    Leave,
+    /// The session has ended, either because the pointer was released or the drag was
+    /// cancelled. Delivered to the originating (source) view.
+    End,
+    /// The pointer was released over an [`on_drop`](DragExt::on_drop) target while a session
+    /// was active, whatever the payload's type. Delivered to that target after the payload has
+    /// been taken out of the session.
+    Drop,
+}
+
+/// A payload slot shared between every [`DragSessionEvent`] dispatched during one drag session.
+///
+/// Cloning this is cheap and safe to do for every informational dispatch (`Start`/`Enter`/
+/// `Over`/`Leave`/`End`): any number of clones may exist. Ownership is handed to a target not
+/// by counting references but by [`RefCell::take`]ing the slot's contents, which is correct
+/// regardless of how many clones are alive or whether some dispatch is deferred.
+type PayloadSlot = Rc<RefCell<Option<Box<dyn Any>>>>;
+
+/// An event delivered while an intra-application drag session, started by
+/// [`draggable`](DragExt::draggable), is active.
+#[derive(Clone)]
+pub struct DragSessionEvent {
+    /// Which part of the drag session this event represents.
+    pub phase: DragPhase,
+    /// (x, y) coordinates in pixels relative to the top-left corner of the window.
+    pub position: Point,
+    /// The dragged payload. Targets inspect this with [`DragSessionEvent::with_payload`], or
+    /// take ownership with [`DragSessionEvent::downcast`]. Both return `None` once the payload
+    /// has been taken, which an [`on_drop`](DragExt::on_drop) target does on release, before
+    /// [`DragPhase::End`] and [`DragPhase::Drop`] are dispatched.
+    payload: PayloadSlot,
+}
+
+impl fmt::Debug for DragSessionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragSessionEvent")
+            .field("phase", &self.phase)
+            .field("position", &self.position)
+            .field("payload", &"..")
+            .finish()
+    }
+}
+
+impl DragSessionEvent {
+    /// Inspect the payload as `T`, if the session is carrying that type and it hasn't already
+    /// been taken by a [`DragSessionEvent::downcast`] call elsewhere or by an
+    /// [`on_drop`](DragExt::on_drop) target on release.
+    pub fn with_payload<T: Any, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let slot = self.payload.borrow();
+        slot.as_ref()?.downcast_ref::<T>().map(f)
+    }
+
+    /// Take ownership of the payload as `T`.
+    ///
+    /// Returns `None` if the session is carrying a different type, or if the payload has
+    /// already been taken (by this call happening twice, or by the target that claimed the
+    /// drop). Unlike counting references, this works no matter how many clones of this event
+    /// exist or in what order they run.
+    pub fn downcast<T: Any>(&self) -> Option<T> {
+        let boxed = self.payload.borrow_mut().take()?;
+        boxed.downcast::<T>().ok().map(|b| *b)
+    }
+}
+
+/// State for the drag session that is currently in progress, if any.
+pub(crate) struct ActiveDragSession {
+    pub(crate) source: ViewId,
+    pub(crate) payload: PayloadSlot,
+    pub(crate) start: Point,
+}
+
+thread_local! {
+    pub(crate) static ACTIVE_DRAG: RefCell<Option<ActiveDragSession>> = const { RefCell::new(None) };
+}
+
+/// Is a typed drag session currently in progress?
+pub fn is_dragging() -> bool {
+    ACTIVE_DRAG.with(|drag| drag.borrow().is_some())
+}
+
+pub(crate) fn active_drag_payload() -> Option<PayloadSlot> {
+    ACTIVE_DRAG.with(|drag| drag.borrow().as_ref().map(|d| d.payload.clone()))
+}
+
+/// Clear the active session, if any, and hand back its state.
+pub(crate) fn end_active_drag() -> Option<ActiveDragSession> {
+    ACTIVE_DRAG.with(|drag| drag.borrow_mut().take())
+}
+
+/// Decorators for making a view draggable and for accepting drops, mirroring
+/// [`Decorators`](crate::views::Decorators) but specific to the typed drag-session subsystem.
+pub trait DragExt: crate::views::Decorators + Sized + 'static {
+    /// Make this view the source of a typed drag session.
+    ///
+    /// After a pointer-down followed by pointer movement past a small threshold, a session
+    /// is started carrying the value returned by `payload_fn`. While the session is active,
+    /// this view receives [`Event::Drag`](crate::event::Event::Drag) with [`DragPhase::Start`].
+    /// [`DragPhase::End`] is delivered once the session ends, whether a target claimed it (via
+    /// [`on_drop`](DragExt::on_drop)) or the pointer was released with no target under it.
+    fn draggable<T: Any + 'static>(self, payload_fn: impl Fn() -> T + 'static) -> Self {
+        let id = self.id();
+        let down_at = Rc::new(RefCell::new(None::<Point>));
+
+        let start_down_at = down_at.clone();
+        let view = self.on_event(EventListener::PointerDown, move |e| {
+            if let Event::Pointer(PointerEvent::Down { state, .. }) = e {
+                *start_down_at.borrow_mut() = Some(state.position);
+            }
+            EventPropagation::Continue
+        });
+
+        let move_down_at = down_at.clone();
+        let view = view.on_event(EventListener::PointerMove, move |e| {
+            let Event::Pointer(PointerEvent::Move(update)) = e else {
+                return EventPropagation::Continue;
+            };
+            let position = update.current.position;
+
+            if is_dragging() {
+                return EventPropagation::Continue;
+            }
+
+            let Some(origin) = *move_down_at.borrow() else {
+                return EventPropagation::Continue;
+            };
+            if origin.distance(position) < DRAG_START_THRESHOLD {
+                return EventPropagation::Continue;
+            }
+
+            // A session is starting: clear the pointer-down origin so this same gesture (or a
+            // later button-up hover move, which carries no fresh `PointerDown`) can't re-enter
+            // this branch and spuriously start another session.
+            *move_down_at.borrow_mut() = None;
+
+            let payload: Box<dyn Any> = Box::new(payload_fn());
+            let slot: PayloadSlot = Rc::new(RefCell::new(Some(payload)));
+            ACTIVE_DRAG.with(|drag| {
+                *drag.borrow_mut() = Some(ActiveDragSession {
+                    source: id,
+                    payload: slot.clone(),
+                    start: origin,
+                });
+            });
+            id.add_event(Event::Drag(DragSessionEvent {
+                phase: DragPhase::Start,
+                position,
+                payload: slot,
+            }));
+            EventPropagation::Continue
+        });
+
+        // Fallback: if the session is still unclaimed when the pointer goes up over the
+        // source itself (e.g. the drag never reached a drop target), end it as a cancel.
+        // `end_active_drag` is idempotent-safe: once a target's `on_drop` has already claimed
+        // the session, this is a no-op. Also resets `down_at` so a plain click, or the next
+        // hover move with no button held, can't be mistaken for the start of a new drag.
+        view.on_event_stop(EventListener::PointerUp, move |_| {
+            *down_at.borrow_mut() = None;
+            if let Some(session) = end_active_drag() {
+                if session.source == id {
+                    id.add_event(Event::Drag(DragSessionEvent {
+                        phase: DragPhase::End,
+                        position: session.start,
+                        payload: session.payload,
+                    }));
+                }
+            }
+        })
+    }
+
+    /// Accept drops of typed payloads of type `T`.
+    ///
+    /// While a session is active and the pointer is within this view's bounds, the view
+    /// receives [`DragPhase::Enter`], then [`DragPhase::Over`] on further movement, and
+    /// [`DragPhase::Leave`] if the pointer leaves without dropping. `on_drop` is called with
+    /// the downcast payload when the pointer is released here while a session carrying a `T`
+    /// is active; sessions carrying other payload types are ignored (but still end normally).
+    fn on_drop<T: Any + 'static>(self, mut on_drop: impl FnMut(T) + 'static) -> Self {
+        let id = self.id();
+        let hovering = Rc::new(Cell::new(false));
+
+        let enter_hovering = hovering.clone();
+        let view = self.on_event(EventListener::PointerEnter, move |e| {
+            if is_dragging() {
+                enter_hovering.set(true);
+                if let (Some(position), Some(payload)) = (e.point(), active_drag_payload()) {
+                    id.add_event(Event::Drag(DragSessionEvent {
+                        phase: DragPhase::Enter,
+                        position,
+                        payload,
+                    }));
+                }
+            }
+            EventPropagation::Continue
+        });
+
+        let move_hovering = hovering.clone();
+        let view = view.on_event(EventListener::PointerMove, move |e| {
+            if move_hovering.get() && is_dragging() {
+                if let (Some(position), Some(payload)) = (e.point(), active_drag_payload()) {
+                    id.add_event(Event::Drag(DragSessionEvent {
+                        phase: DragPhase::Over,
+                        position,
+                        payload,
+                    }));
+                }
+            }
+            EventPropagation::Continue
+        });
+
+        let leave_hovering = hovering.clone();
+        let view = view.on_event(EventListener::PointerLeave, move |e| {
+            if leave_hovering.replace(false) {
+                if let (Some(position), Some(payload)) = (e.point(), active_drag_payload()) {
+                    id.add_event(Event::Drag(DragSessionEvent {
+                        phase: DragPhase::Leave,
+                        position,
+                        payload,
+                    }));
+                }
+            }
+            EventPropagation::Continue
+        });
+
+        view.on_event_stop(EventListener::PointerUp, move |e| {
+            if !hovering.replace(false) {
+                return;
+            }
+            let Some(position) = e.point() else {
+                return;
+            };
+            // Claim the session here: the target that was actually hovered at release time is
+            // the one entitled to the payload.
+            let Some(session) = end_active_drag() else {
+                return;
+            };
+
+            // Take the payload directly out of the shared slot. This succeeds regardless of
+            // how many clones of the slot exist or whether the dispatches below run
+            // synchronously, unlike relying on a reference count reaching exactly one.
+            if let Some(value) = session
+                .payload
+                .borrow_mut()
+                .take()
+                .and_then(|boxed| boxed.downcast::<T>().ok())
+                .map(|boxed| *boxed)
+            {
+                on_drop(value);
+            }
+
+            // Informational only from here: the payload slot is empty once claimed above, so
+            // these just notify that the session ended and where it was dropped.
+            session.source.add_event(Event::Drag(DragSessionEvent {
+                phase: DragPhase::End,
+                position,
+                payload: session.payload.clone(),
+            }));
+            id.add_event(Event::Drag(DragSessionEvent {
+                phase: DragPhase::Drop,
+                position,
+                payload: session.payload,
+            }));
+        })
+    }
+}
+
+impl<V: crate::views::Decorators + 'static> DragExt for V {}
@@ -1,14 +1,133 @@
+use std::cell::Cell;
 use std::path::PathBuf;
 
 use peniko::kurbo::Point;
+use ui_events::keyboard::Modifiers;
+
+use crate::event::{Event, EventListener, EventPropagation};
+use crate::id::ViewId;
+
+/// The contents of a platform drag-and-drop operation.
+///
+/// Generalizes over the kinds of data another application (or the OS shell) can place on the
+/// drag pasteboard, since a drag can carry dragged text, a URL, or an image just as easily as
+/// file paths.
+#[derive(Clone, Debug, Default)]
+pub enum DragData {
+    /// Paths of the file(s) being dragged.
+    Files(Vec<PathBuf>),
+    /// Plain text being dragged, e.g. a text selection from another application.
+    Text(String),
+    /// A URL being dragged, e.g. from a browser's address bar.
+    Url(String),
+    /// An image being dragged.
+    Image(peniko::Image),
+    /// No data in a format floem understands was found on the platform pasteboard.
+    #[default]
+    None,
+}
+
+impl DragData {
+    /// Build the `DragData` for a platform drag update from the paths winit hands us.
+    ///
+    /// Called by [`crate::window_event::DragDropTranslator`] from the window backend's
+    /// `HoveredFile`/`DroppedFile` handling. Paths are the one pasteboard format every backend
+    /// (macOS, Windows, X11/Wayland) already surfaces through winit, so `DragData::Files` is
+    /// always populated correctly. Text, URL, and image formats are not read off the
+    /// pasteboard: winit does not expose them, so nothing here builds [`DragData::Text`],
+    /// [`DragData::Url`], or [`DragData::Image`], and a drag carrying only those formats
+    /// produces no `HoveredFile`/`DroppedFile` and so no [`FileDragEvent`] at all. Empty
+    /// `paths` give [`DragData::None`].
+    pub(crate) fn from_paths(paths: Vec<PathBuf>) -> Self {
+        if paths.is_empty() {
+            DragData::None
+        } else {
+            DragData::Files(paths)
+        }
+    }
+}
+
+/// The effect a drop target is willing to perform with a dragged file.
+///
+/// Returned from [`FileDropExt::on_drop_over`] after each `DragEntered`/`DragMoved`. The window
+/// layer uses it only to decide whether the following `DragDropped` is delivered; it is not
+/// passed on to the platform, so the native drag cursor does not change with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropEffect {
+    /// The drop will not be accepted.
+    #[default]
+    None,
+    /// The dragged files will be copied.
+    Copy,
+    /// The dragged files will be moved.
+    Move,
+    /// The dragged files represent a link or shortcut to the source.
+    Link,
+}
+
+thread_local! {
+    static DROP_EFFECT: Cell<DropEffect> = const { Cell::new(DropEffect::None) };
+}
+
+/// Read back (and reset) the [`DropEffect`] the most recent `on_drop_over` handler returned
+/// for the current `DragEntered`/`DragMoved`, so the window layer can answer the platform's
+/// drag update and, on [`DropEffect::None`], suppress the subsequent `DragDropped`.
+pub(crate) fn take_drop_effect() -> DropEffect {
+    DROP_EFFECT.with(|cell| cell.replace(DropEffect::None))
+}
+
+/// Decorator for negotiating whether a view will accept a file drop, and with what effect.
+pub trait FileDropExt: crate::views::Decorators + Sized + 'static {
+    /// Called with [`FileDragEvent::DragEntered`] and [`FileDragEvent::DragMoved`]. The
+    /// returned [`DropEffect`] decides whether the following [`FileDragEvent::DragDropped`] is
+    /// delivered ([`DropEffect::None`] suppresses it). It is not passed on to the platform, so
+    /// the native drag cursor does not reflect it.
+    fn on_drop_over(self, mut f: impl FnMut(&FileDragEvent) -> DropEffect + 'static) -> Self {
+        self.on_event(EventListener::DroppedFile, move |e| {
+            if let Event::FileDrag(
+                drag @ (FileDragEvent::DragEntered { .. } | FileDragEvent::DragMoved { .. }),
+            ) = e
+            {
+                DROP_EFFECT.with(|cell| cell.set(f(drag)));
+            }
+            EventPropagation::Continue
+        })
+    }
+}
+
+impl<V: crate::views::Decorators + 'static> FileDropExt for V {}
+
+/// Dispatch a `DragEntered`/`DragMoved` update to `view_id` and read back the [`DropEffect`]
+/// an `on_drop_over` handler on (or under) that view chose. The window backend's
+/// `WindowEvent::HoveredFile` handling keeps it for [`dispatch_drag_dropped`]; it is not sent
+/// to the platform.
+pub(crate) fn dispatch_drag_update(view_id: ViewId, event: FileDragEvent) -> DropEffect {
+    view_id.add_event(Event::FileDrag(event));
+    take_drop_effect()
+}
+
+/// Dispatch a `DragDropped` event for `view_id`, honoring the most recently negotiated
+/// [`DropEffect`]: the window backend's `WindowEvent::DroppedFile` handler should call this
+/// with the `DropEffect` last returned from [`dispatch_drag_update`] for this drag, and a
+/// [`DropEffect::None`] suppresses delivery, since no handler along the way was willing to
+/// accept the drop.
+pub(crate) fn dispatch_drag_dropped(view_id: ViewId, event: FileDragEvent, effect: DropEffect) {
+    if effect == DropEffect::None {
+        return;
+    }
+    view_id.add_event(Event::FileDrag(event));
+}
 
 /// A standard `DragEvent` for file drag events.
 #[derive(Clone, Debug)]
 pub enum FileDragEvent {
     /// A file drag operation has entered the window.
     DragEntered {
-        /// List of paths that are being dragged onto the window.
-        paths: Vec<PathBuf>,
+        /// The data being dragged onto the window, read from the platform pasteboard.
+        data: DragData,
+        /// The keyboard modifiers held at the moment of the event, letting a target
+        /// distinguish copy-vs-move intent from held modifier keys.
+        modifiers: Modifiers,
         /// (x,y) coordinates in pixels relative to the top-left corner of the window. May be
         /// negative on some platforms if something is dragged over a window's decorations (title
         /// bar, frame, etc).
@@ -16,6 +135,8 @@ pub enum FileDragEvent {
     },
     /// A file drag operation has moved over the window.
     DragMoved {
+        /// The keyboard modifiers held at the moment of the event.
+        modifiers: Modifiers,
         /// (x,y) coordinates in pixels relative to the top-left corner of the window. May be
         /// negative on some platforms if something is dragged over a window's decorations (title
         /// bar, frame, etc).
@@ -23,8 +144,10 @@ pub enum FileDragEvent {
     },
     /// The file drag operation has dropped file(s) on the window.
     DragDropped {
-        /// List of paths that are being dragged onto the window.
-        paths: Vec<PathBuf>,
+        /// The data that was dragged onto the window, read from the platform pasteboard.
+        data: DragData,
+        /// The keyboard modifiers held at the moment of the event.
+        modifiers: Modifiers,
         /// (x,y) coordinates in pixels relative to the top-left corner of the window. May be
         /// negative on some platforms if something is dragged over a window's decorations (title
         /// bar, frame, etc).
@@ -32,6 +155,8 @@ pub enum FileDragEvent {
     },
     /// The file drag operation has been cancelled or left the window.
     DragLeft {
+        /// The keyboard modifiers held at the moment of the event.
+        modifiers: Modifiers,
         /// (x,y) coordinates in pixels relative to the top-left corner of the window. May be
         /// negative on some platforms if something is dragged over a window's decorations (title
         /// bar, frame, etc).
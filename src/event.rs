@@ -5,7 +5,10 @@ use ui_events::{
 };
 use winit::window::Theme;
 
+use crate::drag::{DragPhase, DragSessionEvent};
 use crate::dropped_file::FileDragEvent;
+use crate::hover::HoverPhase;
+use crate::id::ViewId;
 
 /// Control whether an event will continue propagating or whether it should stop.
 pub enum EventPropagation {
@@ -42,17 +45,17 @@ pub enum EventListener {
     DoubleClick,
     /// Receives [`Event::PointerUp`]
     SecondaryClick,
-    /// Receives [`Event::PointerMove`]
+    /// Receives [`Event::Drag`] with [`DragPhase::Start`]
     DragStart,
-    /// Receives [`Event::PointerUp`]
+    /// Receives [`Event::Drag`] with [`DragPhase::End`]
     DragEnd,
-    /// Receives [`Event::PointerMove`]
+    /// Receives [`Event::Drag`] with [`DragPhase::Over`]
     DragOver,
-    /// Receives [`Event::PointerMove`]
+    /// Receives [`Event::Drag`] with [`DragPhase::Enter`]
     DragEnter,
-    /// Receives [`Event::PointerMove`]
+    /// Receives [`Event::Drag`] with [`DragPhase::Leave`]
     DragLeave,
-    /// Receives [`Event::PointerUp`]
+    /// Receives [`Event::Drag`] with [`DragPhase::Drop`]
     Drop,
     /// Receives [`Event::PointerDown`]
     PointerDown,
@@ -92,12 +95,16 @@ pub enum EventListener {
     WindowGotFocus,
     /// Receives [`Event::WindowLostFocus`]
     WindowLostFocus,
+    /// Receives [`Event::WindowPointerLeft`]
+    WindowPointerLeft,
     /// Receives [`Event::WindowMaximizeChanged`]
     WindowMaximizeChanged,
     /// Receives [`Event::WindowScaleChanged`]
     WindowScaleChanged,
     /// Receives [`Event::DroppedFile`]
     DroppedFile,
+    /// Receives [`Event::PointerHover`]
+    PointerHover,
 }
 
 pub type PointerEvent = ui_events::pointer::PointerEvent<Point>;
@@ -106,6 +113,17 @@ pub type PointerEvent = ui_events::pointer::PointerEvent<Point>;
 pub enum Event {
     Pointer(PointerEvent),
     FileDrag(FileDragEvent),
+    /// An event in an intra-application typed drag session, started with
+    /// [`draggable`](crate::drag::DragExt::draggable).
+    Drag(DragSessionEvent),
+    /// A dwell-based hover transition, started with [`on_hover`](crate::hover::HoverExt::on_hover).
+    PointerHover {
+        /// Which part of the dwell period this event represents.
+        phase: HoverPhase,
+        /// The pointer position that triggered this phase. Not adjusted by [`Event::transform`]
+        /// since it is informational only, not the event's own coordinate.
+        position: Point,
+    },
     Key(ui_events::keyboard::KeyboardEvent),
     ImeEnabled,
     ImeDisabled,
@@ -116,6 +134,10 @@ pub enum Event {
     ImeCommit(String),
     WindowGotFocus,
     WindowLostFocus,
+    /// The pointer has left the window's bounds entirely, as opposed to leaving an individual
+    /// view (see [`Event::Pointer`]'s `Leave`). Useful for dismissing tooltips, hover popovers,
+    /// or drag previews that a final in-bounds `PointerMove` would otherwise leave stuck.
+    WindowPointerLeft,
     WindowClosed,
     WindowResized(Size),
     WindowMoved(Point),
@@ -178,6 +200,7 @@ impl Event {
             | Event::WindowMaximizeChanged(_)
             | Event::WindowScaleChanged(_)
             | Event::WindowLostFocus
+            | Event::WindowPointerLeft
             | Event::FileDrag(FileDragEvent::DragDropped { .. }) => true,
             Event::Pointer(_)
             | Event::FocusGained
@@ -191,6 +214,8 @@ impl Event {
                 | FileDragEvent::DragMoved { .. }
                 | FileDragEvent::DragLeft { .. },
             )
+            | Event::Drag(_)
+            | Event::PointerHover { .. }
             | Event::Key(_) => false,
             // Event::PinchGesture(_)
         }
@@ -204,12 +229,14 @@ impl Event {
             | Event::Pointer(PointerEvent::Scroll { state, .. }) => Some(state.position),
             Event::FileDrag(
                 FileDragEvent::DragEntered { position, .. }
-                | FileDragEvent::DragMoved { position }
+                | FileDragEvent::DragMoved { position, .. }
                 | FileDragEvent::DragDropped { position, .. }
                 | FileDragEvent::DragLeft {
                     position: Some(position),
+                    ..
                 },
             ) => Some(*position),
+            Event::Drag(DragSessionEvent { position, .. }) => Some(*position),
             _ => None,
         }
     }
@@ -228,19 +255,23 @@ impl Event {
             }
             Event::FileDrag(
                 FileDragEvent::DragEntered { position, .. }
-                | FileDragEvent::DragMoved { position }
+                | FileDragEvent::DragMoved { position, .. }
                 | FileDragEvent::DragDropped { position, .. }
                 | FileDragEvent::DragLeft {
                     position: Some(position),
+                    ..
                 },
             ) => {
                 *position = transform.inverse() * *position;
             }
+            Event::Drag(DragSessionEvent { position, .. }) => {
+                *position = transform.inverse() * *position;
+            }
             // Event::PinchGesture(_) => {}
             Event::Pointer(PointerEvent::Cancel(_))
             | Event::Pointer(PointerEvent::Leave(_))
             | Event::Pointer(PointerEvent::Enter(_))
-            | Event::FileDrag(FileDragEvent::DragLeft { position: None })
+            | Event::FileDrag(FileDragEvent::DragLeft { position: None, .. })
             | Event::Key(_)
             | Event::FocusGained
             | Event::FocusLost
@@ -255,7 +286,9 @@ impl Event {
             | Event::WindowMaximizeChanged(_)
             | Event::WindowScaleChanged(_)
             | Event::WindowGotFocus
-            | Event::WindowLostFocus => {}
+            | Event::WindowLostFocus
+            | Event::WindowPointerLeft
+            | Event::PointerHover { .. } => {}
         }
         self
     }
@@ -289,10 +322,28 @@ impl Event {
             Event::WindowScaleChanged(_) => Some(EventListener::WindowScaleChanged),
             Event::WindowGotFocus => Some(EventListener::WindowGotFocus),
             Event::WindowLostFocus => Some(EventListener::WindowLostFocus),
+            Event::WindowPointerLeft => Some(EventListener::WindowPointerLeft),
+            Event::PointerHover { .. } => Some(EventListener::PointerHover),
             Event::FocusLost => Some(EventListener::FocusLost),
             Event::FocusGained => Some(EventListener::FocusGained),
             Event::ThemeChanged(_) => Some(EventListener::ThemeChanged),
             Event::FileDrag(_) => Some(EventListener::DroppedFile),
+            Event::Drag(DragSessionEvent { phase, .. }) => Some(match phase {
+                DragPhase::Start => EventListener::DragStart,
+                DragPhase::Enter => EventListener::DragEnter,
+                DragPhase::Over => EventListener::DragOver,
+                DragPhase::Leave => EventListener::DragLeave,
+                DragPhase::End => EventListener::DragEnd,
+                DragPhase::Drop => EventListener::Drop,
+            }),
         }
     }
 }
+
+/// Dispatch [`Event::WindowPointerLeft`] to the window's root view. The window backend calls
+/// this from its platform event loop when it reports the cursor crossing out of the window
+/// bounds (e.g. winit's `WindowEvent::CursorLeft`), so apps can deterministically clear
+/// transient hover state such as tooltips and drag previews.
+pub(crate) fn dispatch_window_pointer_left(root: ViewId) {
+    root.add_event(Event::WindowPointerLeft);
+}
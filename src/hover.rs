@@ -0,0 +1,142 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use peniko::kurbo::Point;
+
+use crate::action::exec_after;
+use crate::event::{Event, EventListener, EventPropagation};
+use crate::id::ViewId;
+
+/// The jitter radius, in pixels, within which pointer movement does not reset the hover timer.
+const HOVER_JITTER_RADIUS: f64 = 2.0;
+
+/// Which part of a hover dwell period [`Event::PointerHover`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoverPhase {
+    /// The pointer has stayed within a view's bounds, inside the jitter radius, for the
+    /// configured dwell duration.
+    Started,
+    /// A started hover ended: the pointer left the view, moved beyond the jitter radius,
+    /// pressed, or the window lost the cursor. A hover that ends while still pending its dwell
+    /// period emits no `Ended`.
+    Ended,
+}
+
+struct HoverState {
+    last_position: Point,
+    /// Bumped every time the timer is (re)armed, so a stale `exec_after` callback can tell it
+    /// is no longer the most recent one and skip firing.
+    generation: u64,
+    started: bool,
+}
+
+fn arm(id: ViewId, state: &Rc<RefCell<HoverState>>, position: Point, duration: Duration) {
+    let generation = {
+        let mut s = state.borrow_mut();
+        s.last_position = position;
+        s.generation += 1;
+        s.generation
+    };
+    let state = state.clone();
+    exec_after(duration, move |_| {
+        let mut s = state.borrow_mut();
+        if s.generation != generation {
+            return;
+        }
+        s.started = true;
+        drop(s);
+        id.add_event(Event::PointerHover {
+            phase: HoverPhase::Started,
+            position,
+        });
+    });
+}
+
+fn end_hover(id: ViewId, state: &Rc<RefCell<HoverState>>, position: Point) {
+    let mut s = state.borrow_mut();
+    // Invalidate any timer still in flight, and only emit `Ended` if a hover had actually
+    // started (a hover still pending its dwell period simply never begins).
+    s.generation += 1;
+    let was_started = std::mem::replace(&mut s.started, false);
+    drop(s);
+    if was_started {
+        id.add_event(Event::PointerHover {
+            phase: HoverPhase::Ended,
+            position,
+        });
+    }
+}
+
+/// Decorator for a dwell-based hover capability, driven by elapsed time rather than raw
+/// pointer enter/leave, so tooltips and hover popovers appear only after an intentional pause.
+pub trait HoverExt: crate::views::Decorators + Sized + 'static {
+    /// Emit [`HoverPhase::Started`] once the pointer has stayed within this view's bounds,
+    /// without moving beyond a small jitter radius, for `duration`. Emit [`HoverPhase::Ended`]
+    /// once a started hover ends, because the pointer left, moved beyond the jitter radius,
+    /// pressed, or the window lost the cursor; a hover still pending its dwell period ends
+    /// without emitting anything.
+    fn on_hover(self, duration: Duration, mut handler: impl FnMut(HoverPhase, Point) + 'static) -> Self {
+        let id = self.id();
+        let state = Rc::new(RefCell::new(HoverState {
+            last_position: Point::ZERO,
+            generation: 0,
+            started: false,
+        }));
+
+        let enter_state = state.clone();
+        let view = self.on_event(EventListener::PointerEnter, move |e| {
+            if let Some(position) = e.point() {
+                arm(id, &enter_state, position, duration);
+            }
+            EventPropagation::Continue
+        });
+
+        let move_state = state.clone();
+        let view = view.on_event(EventListener::PointerMove, move |e| {
+            let Some(position) = e.point() else {
+                return EventPropagation::Continue;
+            };
+            let jittered = move_state.borrow().last_position.distance(position) > HOVER_JITTER_RADIUS;
+            if jittered {
+                end_hover(id, &move_state, position);
+                arm(id, &move_state, position, duration);
+            }
+            EventPropagation::Continue
+        });
+
+        let leave_state = state.clone();
+        let view = view.on_event(EventListener::PointerLeave, move |_| {
+            let position = leave_state.borrow().last_position;
+            end_hover(id, &leave_state, position);
+            EventPropagation::Continue
+        });
+
+        let down_state = state.clone();
+        let view = view.on_event(EventListener::PointerDown, move |_| {
+            let position = down_state.borrow().last_position;
+            end_hover(id, &down_state, position);
+            EventPropagation::Continue
+        });
+
+        // The window backend emits `Event::WindowPointerLeft` via
+        // `crate::window_event::DragDropTranslator::handle_window_event` on winit's
+        // `WindowEvent::CursorLeft`, so this dismisses a stuck hover when the cursor leaves the
+        // window entirely rather than just this particular view.
+        let window_left_state = state.clone();
+        let view = view.on_event(EventListener::WindowPointerLeft, move |_| {
+            let position = window_left_state.borrow().last_position;
+            end_hover(id, &window_left_state, position);
+            EventPropagation::Continue
+        });
+
+        view.on_event(EventListener::PointerHover, move |e| {
+            if let Event::PointerHover { phase, position } = e {
+                handler(*phase, *position);
+            }
+            EventPropagation::Continue
+        })
+    }
+}
+
+impl<V: crate::views::Decorators + 'static> HoverExt for V {}
@@ -0,0 +1,89 @@
+//! Translates platform `winit::event::WindowEvent`s into floem's file-drag and window-pointer
+//! events. This is the window backend's consumer for the dispatch helpers defined in
+//! [`crate::dropped_file`] and [`crate::event`] — the piece the drop-effect negotiation and
+//! window-pointer-leave features depend on to ever actually fire.
+
+use std::path::PathBuf;
+
+use peniko::kurbo::Point;
+use ui_events::keyboard::Modifiers;
+use winit::event::WindowEvent;
+
+use crate::dropped_file::{dispatch_drag_dropped, dispatch_drag_update, DragData, DropEffect, FileDragEvent};
+use crate::event::dispatch_window_pointer_left;
+use crate::id::ViewId;
+
+/// Per-window state needed to translate winit's one-path-at-a-time `HoveredFile`/`DroppedFile`
+/// events into floem's batched [`FileDragEvent`]s, and to remember the [`DropEffect`] last
+/// negotiated for the drag in progress. `DragEntered` carries the first hovered path; each
+/// further `HoveredFile` is reported as `DragMoved`, and `DragDropped` carries every hovered and
+/// dropped path once.
+#[derive(Default)]
+pub(crate) struct DragDropTranslator {
+    paths: Vec<PathBuf>,
+    position: Point,
+    effect: DropEffect,
+}
+
+impl DragDropTranslator {
+    /// Handle a single `WindowEvent`, dispatching the corresponding floem event to `root` (the
+    /// window's root view): feeding the negotiated [`DropEffect`] back so `DroppedFile` can be
+    /// suppressed when nothing under the pointer was willing to accept the drag, and emitting
+    /// [`Event::WindowPointerLeft`](crate::event::Event::WindowPointerLeft) when the platform
+    /// reports the cursor leaving the window.
+    ///
+    /// `modifiers` is the window's current keyboard modifier state, tracked separately from
+    /// `WindowEvent::ModifiersChanged`.
+    pub(crate) fn handle_window_event(&mut self, root: ViewId, event: &WindowEvent, modifiers: Modifiers) {
+        match event {
+            WindowEvent::HoveredFile(path) => {
+                let first = self.paths.is_empty();
+                self.paths.push(path.clone());
+                let data = DragData::from_paths(self.paths.clone());
+                let drag_event = if first {
+                    FileDragEvent::DragEntered {
+                        data,
+                        modifiers,
+                        position: self.position,
+                    }
+                } else {
+                    FileDragEvent::DragMoved {
+                        modifiers,
+                        position: self.position,
+                    }
+                };
+                self.effect = dispatch_drag_update(root, drag_event);
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.paths.clear();
+                self.effect = DropEffect::None;
+                // `DragLeft` is purely informational (no accept/reject to negotiate), so it
+                // always goes through, unlike `DragDropped`.
+                root.add_event(crate::event::Event::FileDrag(FileDragEvent::DragLeft {
+                    modifiers,
+                    position: Some(self.position),
+                }));
+            }
+            WindowEvent::DroppedFile(path) => {
+                if !self.paths.contains(path) {
+                    self.paths.push(path.clone());
+                }
+                let data = DragData::from_paths(std::mem::take(&mut self.paths));
+                let drag_event = FileDragEvent::DragDropped {
+                    data,
+                    modifiers,
+                    position: self.position,
+                };
+                dispatch_drag_dropped(root, drag_event, self.effect);
+                self.effect = DropEffect::None;
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                self.position = Point::new(position.x, position.y);
+            }
+            WindowEvent::CursorLeft { .. } => {
+                dispatch_window_pointer_left(root);
+            }
+            _ => {}
+        }
+    }
+}